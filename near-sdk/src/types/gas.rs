@@ -1,5 +1,5 @@
 use std::str::FromStr;
-use std::num::{ParseIntError, IntErrorKind};
+use std::num::ParseIntError;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use core::ops;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -28,6 +28,46 @@ impl Gas {
   pub fn from_tgas(tgas: u64) -> Gas {
     ONE_TGAS * tgas.into()
   }
+
+  /// Creates a `Gas` from a raw `u64` amount of gas units.
+  pub fn from_gas(gas: u64) -> Gas {
+    Gas(gas)
+  }
+
+  /// Returns the amount of gas units as a raw `u64`.
+  pub fn as_gas(&self) -> u64 {
+    self.0
+  }
+
+  /// Checked integer addition. Computes `self + rhs`, returning `None` if overflow occurred.
+  pub fn checked_add(self, rhs: Gas) -> Option<Gas> {
+    self.0.checked_add(rhs.0).map(Gas)
+  }
+
+  /// Checked integer subtraction. Computes `self - rhs`, returning `None` if overflow occurred.
+  pub fn checked_sub(self, rhs: Gas) -> Option<Gas> {
+    self.0.checked_sub(rhs.0).map(Gas)
+  }
+
+  /// Checked integer multiplication. Computes `self * rhs`, returning `None` if overflow occurred.
+  pub fn checked_mul(self, rhs: u64) -> Option<Gas> {
+    self.0.checked_mul(rhs).map(Gas)
+  }
+
+  /// Saturating integer addition. Computes `self + rhs`, saturating at `u64::MAX`.
+  pub fn saturating_add(self, rhs: Gas) -> Gas {
+    Gas(self.0.saturating_add(rhs.0))
+  }
+
+  /// Saturating integer subtraction. Computes `self - rhs`, saturating at `0`.
+  pub fn saturating_sub(self, rhs: Gas) -> Gas {
+    Gas(self.0.saturating_sub(rhs.0))
+  }
+
+  /// Saturating integer multiplication. Computes `self * rhs`, saturating at `u64::MAX`.
+  pub fn saturating_mul(self, rhs: u64) -> Gas {
+    Gas(self.0.saturating_mul(rhs))
+  }
 }
 
 impl Serialize for Gas {
@@ -35,6 +75,13 @@ impl Serialize for Gas {
     where
         S: Serializer,
     {
+        // JSON loses precision above 2^53, so human-readable formats still need the
+        // value stringified. Binary formats (e.g. borsh-adjacent bincode, `ron`) can
+        // take the `u64` directly and skip the decimal formatting.
+        if !serializer.is_human_readable() {
+            return serializer.serialize_u64(self.0);
+        }
+
         let mut buf = [0u8; 20];
         let remainder = {
             use std::io::Write;
@@ -55,8 +102,51 @@ impl<'de> Deserialize<'de> for Gas {
     where
         D: Deserializer<'de>,
     {
-        let s: String = Deserialize::deserialize(deserializer)?;
-        s.parse::<u64>().map(Self).map_err(|err| de::Error::custom(err.to_string()))
+        if !deserializer.is_human_readable() {
+            return <u64 as Deserialize>::deserialize(deserializer).map(Self);
+        }
+
+        struct GasVisitor;
+
+        impl<'de> de::Visitor<'de> for GasVisitor {
+            type Value = Gas;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a u64 gas amount, as a number or a decimal string")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Gas(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                u64::try_from(value).map(Gas).map_err(|_| {
+                    de::Error::invalid_value(de::Unexpected::Signed(value), &self)
+                })
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                value.parse::<u64>().map(Gas).map_err(|err| de::Error::custom(err.to_string()))
+            }
+
+            fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_str(value)
+            }
+        }
+
+        deserializer.deserialize_any(GasVisitor)
     }
 }
 
@@ -66,22 +156,101 @@ impl From<u64> for Gas {
     }
 }
 
-fn isNum(c: char) -> bool {
-  match c {
-    '0'..='9' => true,
-    _ => false
-  }
+/// Recognized unit suffixes for [`Gas::from_str`], along with how many decimal digits
+/// they scale the raw gas amount by (used both for the multiplier and for how many
+/// fractional digits a `"1.5 Tgas"`-style literal may carry).
+#[derive(Clone, Copy)]
+enum GasUnit {
+    Gas,
+    Ggas,
+    Tgas,
+}
+
+impl GasUnit {
+    fn scale_digits(self) -> u32 {
+        match self {
+            GasUnit::Gas => 0,
+            GasUnit::Ggas => 9,
+            GasUnit::Tgas => 12,
+        }
+    }
+}
+
+/// Splits a trailing, case-insensitive `gas`/`ggas`/`tgas` unit suffix off of `value`,
+/// returning the remaining (not yet trimmed) numeric prefix and the matched unit.
+/// `tgas`/`ggas` are checked before the bare `gas` suffix they both end with.
+fn split_unit_suffix(value: &str) -> (&str, GasUnit) {
+    let lower = value.to_ascii_lowercase();
+    for (suffix, unit) in [("tgas", GasUnit::Tgas), ("ggas", GasUnit::Ggas), ("gas", GasUnit::Gas)] {
+        if lower.ends_with(suffix) && lower.len() > suffix.len() {
+            return (&value[..value.len() - suffix.len()], unit);
+        }
+    }
+    (value, GasUnit::Gas)
+}
+
+fn empty_err() -> ParseIntError {
+    "".parse::<u64>().unwrap_err()
+}
+
+fn invalid_digit_err() -> ParseIntError {
+    "a".parse::<u64>().unwrap_err()
+}
+
+fn overflow_err() -> ParseIntError {
+    "99999999999999999999999999".parse::<u64>().unwrap_err()
 }
 
 impl FromStr for Gas {
-  type Err = ParseIntError;
-  fn from_str(value: &str) -> Result<Self, Self::Err> {
-    if !value.starts_with(isNum) {
-      return Err(ParseIntError{ kind: IntErrorKind::InvalidDigit })
+    type Err = ParseIntError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(empty_err());
+        }
+
+        let (number, unit) = split_unit_suffix(value);
+        let number = number.trim_end();
+        if number.is_empty() {
+            return Err(empty_err());
+        }
+
+        // Strip `_` grouping characters from the numeric part, same as Rust integer literals.
+        let digits: String = number.chars().filter(|&c| c != '_').collect();
+
+        let (int_part, frac_part) = match digits.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (digits.as_str(), None),
+        };
+
+        let scale_digits = unit.scale_digits();
+        if frac_part.is_some() && scale_digits == 0 {
+            // A fractional amount is only meaningful together with a Ggas/Tgas suffix.
+            return Err(invalid_digit_err());
+        }
+
+        let int_value = int_part.parse::<u64>()?;
+        let scale = 10u64.pow(scale_digits);
+        let scaled = int_value.checked_mul(scale).ok_or_else(overflow_err)?;
+
+        let gas = match frac_part {
+            None => scaled,
+            Some(frac) => {
+                if frac.is_empty() || frac.len() > scale_digits as usize {
+                    return Err(invalid_digit_err());
+                }
+                if !frac.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid_digit_err());
+                }
+                let mut padded = frac.to_string();
+                padded.extend(std::iter::repeat_n('0', scale_digits as usize - frac.len()));
+                let frac_value = padded.parse::<u64>()?;
+                scaled.checked_add(frac_value).ok_or_else(overflow_err)?
+            }
+        };
+
+        Ok(Gas(gas))
     }
-    let int = str::replace(value, "_", "to");
-    Ok(u64::from_str_radix(&int, 10)?.into())
-  }
 }
 
 impl From<Gas> for u64 {
@@ -142,9 +311,118 @@ impl ops::Rem<u64> for Gas {
     }
 }
 
+/// A [`Gas`] value that keeps its undecoded textual token around instead of eagerly
+/// parsing it, much like `serde_json::value::RawValue`. Useful when forwarding gas
+/// amounts (e.g. relaying a batch of promises) that are never inspected, since it
+/// avoids paying to parse and re-serialize a number nobody reads.
+///
+/// This laziness guarantee only holds for the human-readable (JSON) path: Borsh has
+/// no "raw bytes" concept to piggyback on, so `BorshSerialize`/`BorshDeserialize` still
+/// parse the captured token into a `u64` on every round trip.
+///
+/// Only a JSON string token is kept as written. A bare JSON number is decoded into a
+/// `u64` by the deserializer and stored as its decimal string, so it is re-emitted as
+/// a quoted string rather than as the original number token.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawGas(String);
+
+impl RawGas {
+    /// Parses the captured token into a [`Gas`].
+    pub fn parse(&self) -> Result<Gas, ParseIntError> {
+        self.0.parse()
+    }
+}
+
+impl From<Gas> for RawGas {
+    fn from(gas: Gas) -> Self {
+        RawGas(gas.0.to_string())
+    }
+}
+
+impl std::convert::TryFrom<RawGas> for Gas {
+    type Error = ParseIntError;
+
+    fn try_from(raw: RawGas) -> Result<Self, Self::Error> {
+        raw.parse()
+    }
+}
+
+impl Serialize for RawGas {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.0)
+        } else {
+            let gas = self.parse().map_err(serde::ser::Error::custom)?;
+            serializer.serialize_u64(gas.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RawGas {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !deserializer.is_human_readable() {
+            return <u64 as Deserialize>::deserialize(deserializer).map(|value| RawGas(value.to_string()));
+        }
+
+        struct RawGasVisitor;
+
+        impl<'de> de::Visitor<'de> for RawGasVisitor {
+            type Value = RawGas;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a gas amount, as a number or a decimal string")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(RawGas(value.to_string()))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(RawGas(value.to_owned()))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(RawGas(value))
+            }
+        }
+
+        deserializer.deserialize_any(RawGasVisitor)
+    }
+}
+
+impl BorshSerialize for RawGas {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let gas = self.parse().map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+        BorshSerialize::serialize(&gas.0, writer)
+    }
+}
+
+impl BorshDeserialize for RawGas {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let value = <u64 as BorshDeserialize>::deserialize(buf)?;
+        Ok(RawGas(value.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::convert::TryFrom;
 
     fn test_json_ser(val: u64) {
         let gas = Gas(val);
@@ -161,6 +439,35 @@ mod tests {
         test_json_ser(0);
     }
 
+    #[test]
+    fn json_de_accepts_number() {
+        let de: Gas = serde_json::from_str("300000000000000").unwrap();
+        assert_eq!(de.0, 300_000_000_000_000);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(Gas(5).checked_add(Gas(3)), Some(Gas(8)));
+        assert_eq!(Gas(u64::MAX).checked_add(Gas(1)), None);
+        assert_eq!(Gas(5).checked_sub(Gas(3)), Some(Gas(2)));
+        assert_eq!(Gas(1).checked_sub(Gas(2)), None);
+        assert_eq!(Gas(5).checked_mul(3), Some(Gas(15)));
+        assert_eq!(Gas(u64::MAX).checked_mul(2), None);
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        assert_eq!(Gas(u64::MAX).saturating_add(Gas(1)), Gas(u64::MAX));
+        assert_eq!(Gas(1).saturating_sub(Gas(2)), Gas(0));
+        assert_eq!(Gas(u64::MAX).saturating_mul(2), Gas(u64::MAX));
+    }
+
+    #[test]
+    fn test_from_gas_as_gas() {
+        let gas = Gas::from_gas(42);
+        assert_eq!(gas.as_gas(), 42);
+    }
+
     #[test]
     fn test_tgas() {
       assert_eq!(Gas::from_tgas(1), Gas(1_000_000_000_000));
@@ -172,4 +479,47 @@ mod tests {
       assert_eq!(Gas::from_str("1_000_000_000_000").unwrap(), Gas(1_000_000_000_000));
       assert!(matches!(Gas::from_str("A"), Err(_)));
     }
+
+    #[test]
+    fn test_gas_from_str_whitespace_and_sign() {
+      assert_eq!(Gas::from_str("  300000000000000  ").unwrap(), Gas(300_000_000_000_000));
+      assert_eq!(Gas::from_str("+5").unwrap(), Gas(5));
+    }
+
+    #[test]
+    fn test_gas_from_str_unit_suffixes() {
+      assert_eq!(Gas::from_str("1 Tgas").unwrap(), Gas(1_000_000_000_000));
+      assert_eq!(Gas::from_str("1Ggas").unwrap(), Gas(1_000_000_000));
+      assert_eq!(Gas::from_str("5 gas").unwrap(), Gas(5));
+      assert_eq!(Gas::from_str("1.5 Tgas").unwrap(), Gas(1_500_000_000_000));
+      assert_eq!(Gas::from_str("1.5tgas").unwrap(), Gas(1_500_000_000_000));
+      assert_eq!(Gas::from_str("300_000_000_000_000").unwrap(), Gas(300_000_000_000_000));
+    }
+
+    #[test]
+    fn test_gas_from_str_rejects_bad_input() {
+      // fractional amount with no unit suffix is meaningless
+      assert!(matches!(Gas::from_str("1.5"), Err(_)));
+      // more fractional digits than the unit's scale allows
+      assert!(matches!(Gas::from_str("1.0000000000 Ggas"), Err(_)));
+      assert!(matches!(Gas::from_str(""), Err(_)));
+      assert!(matches!(Gas::from_str("   "), Err(_)));
+    }
+
+    #[test]
+    fn raw_gas_json_roundtrip() {
+        let raw: RawGas = serde_json::from_str("\"300000000000000\"").unwrap();
+        assert_eq!(serde_json::to_string(&raw).unwrap(), "\"300000000000000\"");
+        assert_eq!(raw.parse().unwrap(), Gas(300_000_000_000_000));
+
+        let raw: RawGas = serde_json::from_str("300000000000000").unwrap();
+        assert_eq!(raw.parse().unwrap(), Gas(300_000_000_000_000));
+    }
+
+    #[test]
+    fn raw_gas_conversions() {
+        let raw: RawGas = Gas(42).into();
+        assert_eq!(raw.parse().unwrap(), Gas(42));
+        assert_eq!(Gas::try_from(raw).unwrap(), Gas(42));
+    }
 }